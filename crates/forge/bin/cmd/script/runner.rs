@@ -1,10 +1,14 @@
 use super::ScriptResult;
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_eips::eip2930::{AccessList, AccessListItem};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use eyre::Result;
 use forge::{
     constants::CALLER,
     executors::{CallResult, DeployResult, EvmError, ExecutionErr, Executor, RawCallResult},
-    revm::interpreter::{return_ok, InstructionResult},
+    revm::{
+        interpreter::{return_ok, InstructionResult},
+        primitives::SpecId,
+    },
     traces::{TraceKind, Traces},
 };
 use foundry_config::Config;
@@ -17,17 +21,56 @@ pub enum SimulationStage {
     OnChain,
 }
 
+/// A not-yet-simulated queued transaction, as passed to [`ScriptRunner::prepare_ahead`].
+///
+/// Mirrors the `(to, use_zk, zk_tx)` arguments [`ScriptRunner::simulate`] takes for the
+/// transaction it's currently running.
+pub struct QueuedZkTransaction {
+    pub to: Option<Address>,
+    pub use_zk: bool,
+    pub zk_tx: Option<ZkTransactionMetadata>,
+}
+
+/// Default [`ScriptRunner::priority_fee`], in wei (1 gwei). [`ScriptRunner::new`] always starts
+/// from this value; the runner does not sample priority fees from a fork itself.
+const DEFAULT_PRIORITY_FEE: u128 = 1_000_000_000;
+
 /// Drives script execution
 #[derive(Debug)]
 pub struct ScriptRunner {
     pub executor: Executor,
     pub initial_balance: U256,
     pub sender: Address,
+    /// Whether to generate and attach an EIP-2930 access list to committed broadcast
+    /// transactions. Disable this for chains that don't support 2930.
+    pub use_access_list: bool,
+    /// The `maxPriorityFeePerGas` suggested for broadcast transactions, in wei. Defaults to
+    /// [`DEFAULT_PRIORITY_FEE`]; override with [`Self::with_priority_fee`], e.g. with a value
+    /// sampled from a fork's recent priority fees.
+    pub priority_fee: u128,
 }
 
 impl ScriptRunner {
     pub fn new(executor: Executor, initial_balance: U256, sender: Address) -> Self {
-        Self { executor, initial_balance, sender }
+        Self {
+            executor,
+            initial_balance,
+            sender,
+            use_access_list: true,
+            priority_fee: DEFAULT_PRIORITY_FEE,
+        }
+    }
+
+    /// Enables or disables EIP-2930 access list generation for committed broadcast transactions.
+    pub fn with_access_list(mut self, use_access_list: bool) -> Self {
+        self.use_access_list = use_access_list;
+        self
+    }
+
+    /// Overrides the suggested `maxPriorityFeePerGas` tip for committed broadcast transactions.
+    pub fn with_priority_fee(mut self, priority_fee: u128) -> Self {
+        self.priority_fee = priority_fee;
+        self
     }
 
     /// Deploys the libraries and broadcast contract. Calls setUp method if requested.
@@ -59,18 +102,18 @@ impl ScriptRunner {
         self.executor.set_balance(CALLER, U256::MAX)?;
 
         // Deploy libraries
-        let mut traces: Traces = libraries
-            .iter()
-            .filter_map(|code| {
-                let DeployResult { traces, .. } = self
-                    .executor
-                    .deploy(self.sender, code.clone(), U256::ZERO, None)
-                    .expect("couldn't deploy library");
-
-                traces
-            })
-            .map(|traces| (TraceKind::Deployment, traces))
-            .collect();
+        let mut traces: Traces = Vec::with_capacity(libraries.len());
+        for library in libraries {
+            match self.executor.deploy(self.sender, library.clone(), U256::ZERO, None) {
+                Ok(DeployResult { traces: lib_traces, .. }) => {
+                    traces.extend(lib_traces.map(|traces| (TraceKind::Deployment, traces)));
+                }
+                Err(EvmError::Execution(err)) => {
+                    return Ok((Address::ZERO, Self::deploy_failure_result(traces, *err)));
+                }
+                Err(e) => return Err(eyre::eyre!("Failed to deploy library: {e}")),
+            }
+        }
 
         let address = CALLER.create(self.executor.get_nonce(CALLER)?);
 
@@ -79,18 +122,17 @@ impl ScriptRunner {
         self.executor.set_balance(address, self.initial_balance)?;
 
         // Deploy an instance of the contract
-        let DeployResult {
-            address,
-            mut logs,
-            traces: constructor_traces,
-            debug: constructor_debug,
-            ..
-        } = self
-            .executor
-            .deploy(CALLER, code, U256::ZERO, None)
-            .map_err(|err| eyre::eyre!("Failed to deploy script:\n{}", err))?;
-
-        traces.extend(constructor_traces.map(|traces| (TraceKind::Deployment, traces)));
+        let (address, mut logs, constructor_debug) =
+            match self.executor.deploy(CALLER, code, U256::ZERO, None) {
+                Ok(DeployResult { address, logs, traces: constructor_traces, debug, .. }) => {
+                    traces.extend(constructor_traces.map(|traces| (TraceKind::Deployment, traces)));
+                    (address, logs, debug)
+                }
+                Err(EvmError::Execution(err)) => {
+                    return Ok((Address::ZERO, Self::deploy_failure_result(traces, *err)));
+                }
+                Err(e) => return Err(eyre::eyre!("Failed to deploy script:\n{}", e)),
+            };
 
         // Optionally call the `setUp` function
         let (success, gas_used, labeled_addresses, transactions, debug) = if !setup {
@@ -166,6 +208,28 @@ impl ScriptRunner {
         ))
     }
 
+    /// Turns a failed library/script deployment into a `ScriptResult` with `success: false`,
+    /// folding its traces into `traces` under `TraceKind::Deployment`. This gives a user whose
+    /// library or script constructor reverts the same rich trace/log output they'd get from a
+    /// reverting `setUp`, instead of an opaque panic.
+    fn deploy_failure_result(mut traces: Traces, err: ExecutionErr) -> ScriptResult {
+        let ExecutionErr { traces: deploy_traces, labels, logs, debug, gas_used, .. } = err;
+        traces.extend(deploy_traces.map(|traces| (TraceKind::Deployment, traces)));
+
+        ScriptResult {
+            returned: Bytes::new(),
+            success: false,
+            gas_used,
+            labeled_addresses: labels,
+            transactions: None,
+            logs,
+            traces,
+            debug: vec![debug].into_iter().collect(),
+            address: None,
+            ..Default::default()
+        }
+    }
+
     /// We call the `setUp()` function with self.sender, and if there haven't been
     /// any broadcasts, then the EVM cheatcode module hasn't corrected the nonce.
     /// So we have to.
@@ -189,7 +253,42 @@ impl ScriptRunner {
         self.call(self.sender, address, calldata, U256::ZERO, false)
     }
 
+    /// Synchronously pre-warms the executor's bytecode cache for up to `depth` of the upcoming
+    /// queued ZK create transactions, so their factory dependencies are decompressed, hashed and
+    /// validated a little ahead of the transaction that actually needs them.
+    ///
+    /// This runs on the same thread as the rest of `ScriptRunner`, before the current transaction
+    /// executes, not concurrently with it — it's a prefetch pass, not a background pipeline.
+    /// Overlapping it with execution would need the bytecode cache to be shared across a thread
+    /// pool, which `Executor` doesn't support today.
+    ///
+    /// This is purely a cache-warming side effect: it never mutates execution state and must
+    /// never change what a later `simulate` call returns.
+    pub fn prepare_ahead(&mut self, queued: &[QueuedZkTransaction], depth: usize) {
+        for zk_tx in Self::zk_deps_to_prepare(queued, depth) {
+            self.executor.prepare_zk_factory_deps(zk_tx);
+        }
+    }
+
+    /// Selects the factory deps [`Self::prepare_ahead`] should warm: the ZK create transactions
+    /// (no `to`, `use_zk` set) among the first `depth` entries of `queued`.
+    fn zk_deps_to_prepare(
+        queued: &[QueuedZkTransaction],
+        depth: usize,
+    ) -> impl Iterator<Item = &ZkTransactionMetadata> {
+        queued
+            .iter()
+            .take(depth)
+            .filter(|queued| queued.to.is_none() && queued.use_zk)
+            .filter_map(|queued| queued.zk_tx.as_ref())
+    }
+
     /// Runs a broadcastable transaction locally and persists its state.
+    ///
+    /// If `spec_id` is set, the executor's hardfork is swapped to it for the duration of this
+    /// call and restored afterwards, the same way [`Self::search_optimal_gas_usage`] snapshots
+    /// and restores `env.tx.gas_limit`. This lets a single multichain script simulate each
+    /// broadcastable transaction under the fork its target network is actually on.
     pub fn simulate(
         &mut self,
         from: Address,
@@ -197,12 +296,35 @@ impl ScriptRunner {
         calldata: Option<Bytes>,
         value: Option<U256>,
         (use_zk, zk_tx): (bool, Option<ZkTransactionMetadata>),
+        spec_id: Option<SpecId>,
     ) -> Result<ScriptResult> {
         self.executor.use_zk = use_zk;
         if let Some(zk_tx) = zk_tx {
             self.executor.setup_zk_tx(zk_tx);
         }
 
+        let prev_spec_id = spec_id.map(|spec_id| {
+            let prev = self.executor.spec_id();
+            self.executor.set_spec_id(spec_id);
+            prev
+        });
+
+        let result = self.simulate_inner(from, to, calldata, value);
+
+        if let Some(prev_spec_id) = prev_spec_id {
+            self.executor.set_spec_id(prev_spec_id);
+        }
+
+        result
+    }
+
+    fn simulate_inner(
+        &mut self,
+        from: Address,
+        to: Option<Address>,
+        calldata: Option<Bytes>,
+        value: Option<U256>,
+    ) -> Result<ScriptResult> {
         if let Some(to) = to {
             self.call(from, to, calldata.unwrap_or_default(), value.unwrap_or(U256::ZERO), true)
         } else if to.is_none() {
@@ -224,6 +346,15 @@ impl ScriptRunner {
                 Err(e) => eyre::bail!("Failed deploying contract: {e:?}"),
             };
 
+            let (max_fee_per_gas, max_priority_fee_per_gas) = if self.executor.use_zk {
+                // The L2 fee model doesn't follow EIP-1559, so ZK transactions opt out of
+                // suggested 1559 fees entirely.
+                (None, None)
+            } else {
+                let (max_fee, priority_fee) = self.suggested_fees();
+                (Some(max_fee), Some(priority_fee))
+            };
+
             Ok(ScriptResult {
                 returned: Bytes::new(),
                 success: address != Address::ZERO,
@@ -238,6 +369,8 @@ impl ScriptRunner {
                     .unwrap_or_default(),
                 debug: vec![debug].into_iter().collect(),
                 address: Some(address),
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
                 ..Default::default()
             })
         } else {
@@ -258,9 +391,41 @@ impl ScriptRunner {
         calldata: Bytes,
         value: U256,
         commit: bool,
+    ) -> Result<ScriptResult> {
+        // `env.tx.gas_price`/`access_list` persist across the whole sequence of broadcastable
+        // transactions in a script, so snapshot them and reset the access list up front, the
+        // same way `search_optimal_gas_usage` snapshots and restores `gas_limit`. Otherwise this
+        // transaction's leading, non-committing probe below (and, if it also commits, its own
+        // gas search) would silently inherit the previous transaction's suggested gas price or
+        // access list, giving it bogus warm/cold gas accounting that has nothing to do with its
+        // own touched storage.
+        //
+        // `call_inner` is fallible and returns early via `?` in several places, so the restore
+        // has to live out here rather than at the end of `call_inner`'s body, the same way
+        // `simulate` restores `prev_spec_id` around the fallible `simulate_inner`.
+        let init_gas_price = self.executor.env.tx.gas_price;
+        let init_access_list = std::mem::take(&mut self.executor.env.tx.access_list);
+
+        let result = self.call_inner(from, to, calldata, value, commit);
+
+        self.executor.env.tx.gas_price = init_gas_price;
+        self.executor.env.tx.access_list = init_access_list;
+
+        result
+    }
+
+    fn call_inner(
+        &mut self,
+        from: Address,
+        to: Address,
+        calldata: Bytes,
+        value: U256,
+        commit: bool,
     ) -> Result<ScriptResult> {
         let mut res = self.executor.call_raw(from, to, calldata.clone(), value)?;
         let mut gas_used = res.gas_used;
+        let mut max_fee_per_gas = None;
+        let mut max_priority_fee_per_gas = None;
 
         // We should only need to calculate realistic gas costs when preparing to broadcast
         // something. This happens during the onchain simulation stage, where we commit each
@@ -269,7 +434,40 @@ impl ScriptRunner {
         // Otherwise don't re-execute, or some usecases might be broken: https://github.com/foundry-rs/foundry/issues/3921
         if commit {
             gas_used = self.search_optimal_gas_usage(&res, from, to, &calldata, value)?;
-            res = self.executor.call_raw_committing(from, to, calldata, value)?;
+
+            // ZK transactions follow an L2 fee model that doesn't speak 1559, so they opt out
+            // of suggested fees and keep using `gas_price` instead.
+            if !self.executor.use_zk {
+                let (max_fee, priority_fee) = self.suggested_fees();
+                // The price actually charged during simulation is capped at `max_fee_per_gas`,
+                // so balance checks stay accurate even if the base fee hasn't caught up yet.
+                let effective_gas_price = std::cmp::min(
+                    max_fee,
+                    self.executor.env.block.basefee.saturating_to::<u128>() + priority_fee,
+                );
+                self.executor.env.tx.gas_price = U256::from(effective_gas_price);
+                max_fee_per_gas = Some(max_fee);
+                max_priority_fee_per_gas = Some(priority_fee);
+            }
+
+            res = self.executor.call_raw_committing(from, to, calldata.clone(), value)?;
+
+            if self.use_access_list {
+                if let Some(access_list) = self.build_access_list(&res, from, to) {
+                    self.executor.env.tx.access_list = access_list.0.clone();
+
+                    // Cold account access costs 2600 gas and cold SLOAD 2100 vs 100 warm, so
+                    // pre-declaring the list lowers execution gas. Re-run the search once with
+                    // it installed so we report the reduced limit.
+                    gas_used = self.search_optimal_gas_usage(&res, from, to, &calldata, value)?;
+
+                    if let Some(transactions) = res.transactions.as_mut() {
+                        for tx in transactions {
+                            tx.transaction.set_access_list(access_list.clone());
+                        }
+                    }
+                }
+            }
         }
 
         let RawCallResult { result, reverted, logs, traces, labels, debug, transactions, .. } = res;
@@ -292,12 +490,72 @@ impl ScriptRunner {
             transactions,
             address: None,
             breakpoints,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
         })
     }
 
+    /// Returns the suggested `(max_fee_per_gas, max_priority_fee_per_gas)` for a broadcast
+    /// transaction, derived from the current block's base fee so the transaction remains
+    /// includable across a couple of base-fee adjustments.
+    fn suggested_fees(&self) -> (u128, u128) {
+        let base_fee = self.executor.env.block.basefee.saturating_to::<u128>();
+        let priority_fee = self.priority_fee;
+        let max_fee = base_fee.saturating_mul(2).saturating_add(priority_fee);
+        (max_fee, priority_fee)
+    }
+
+    /// Builds a deduplicated, sorted EIP-2930 access list from the accounts and storage slots
+    /// touched while committing `res`, including `to` itself even if none of its storage was
+    /// touched.
+    ///
+    /// `from` and the precompiles are always warm by default (EIP-2929), so listing them would
+    /// only cost the 2400/2600 gas of a cold-account declaration for zero benefit; both are
+    /// skipped.
+    fn build_access_list(
+        &self,
+        res: &RawCallResult,
+        from: Address,
+        to: Address,
+    ) -> Option<AccessList> {
+        let state = res.state_changeset.as_ref()?;
+
+        let mut items: Vec<AccessListItem> = state
+            .iter()
+            .filter(|(address, _)| **address != from && !Self::is_precompile(address))
+            .map(|(address, account)| {
+                let mut storage_keys: Vec<B256> =
+                    account.storage.keys().map(|slot| B256::from(*slot)).collect();
+                storage_keys.sort();
+                storage_keys.dedup();
+                AccessListItem { address: *address, storage_keys }
+            })
+            .collect();
+
+        if to != from && !Self::is_precompile(&to) && !items.iter().any(|item| item.address == to)
+        {
+            items.push(AccessListItem { address: to, storage_keys: Vec::new() });
+        }
+
+        items.sort_by_key(|item| item.address);
+
+        if items.is_empty() {
+            None
+        } else {
+            Some(AccessList(items))
+        }
+    }
+
+    /// Whether `address` is one of the well-known precompiles (`0x01`-`0x09`), which are always
+    /// warm and so never worth declaring in an access list.
+    fn is_precompile(address: &Address) -> bool {
+        address.0[..19].iter().all(|&b| b == 0) && (1..=9).contains(&address.0[19])
+    }
+
     /// The executor will return the _exact_ gas value this transaction consumed, setting this value
     /// as gas limit will result in `OutOfGas` so to come up with a better estimate we search over a
-    /// possible range we pick a higher gas limit 3x of a succeeded call should be safe.
+    /// possible range, seeded per [`Self::seed_high_gas_limit`] and only widened further if that
+    /// first probe still runs out of gas.
     ///
     /// This might result in executing the same script multiple times. Depending on the user's goal,
     /// it might be problematic when using `ffi`.
@@ -314,39 +572,179 @@ impl ScriptRunner {
             // store the current gas limit and reset it later
             let init_gas_limit = self.executor.env.tx.gas_limit;
 
-            let mut highest_gas_limit = gas_used * 3;
-            let mut lowest_gas_limit = gas_used;
-            let mut last_highest_gas_limit = highest_gas_limit;
-            while (highest_gas_limit - lowest_gas_limit) > 1 {
-                let mid_gas_limit = (highest_gas_limit + lowest_gas_limit) / 2;
-                self.executor.env.tx.gas_limit = mid_gas_limit;
-                let res = self.executor.call_raw(from, to, calldata.0.clone().into(), value)?;
-                match res.exit_reason {
-                    InstructionResult::Revert |
-                    InstructionResult::OutOfGas |
-                    InstructionResult::OutOfFund => {
-                        lowest_gas_limit = mid_gas_limit;
-                    }
-                    _ => {
-                        highest_gas_limit = mid_gas_limit;
-                        // if last two successful estimations only vary by 10%, we consider this to
-                        // sufficiently accurate
-                        const ACCURACY: u64 = 10;
-                        if (last_highest_gas_limit - highest_gas_limit) * ACCURACY /
-                            last_highest_gas_limit <
-                            1
-                        {
-                            // update the gas
-                            gas_used = highest_gas_limit;
-                            break;
-                        }
-                        last_highest_gas_limit = highest_gas_limit;
-                    }
-                }
+            let mut highest_gas_limit = Self::seed_high_gas_limit(gas_used);
+            while {
+                self.executor.env.tx.gas_limit = highest_gas_limit;
+                let probe = self.executor.call_raw(from, to, calldata.0.clone().into(), value)?;
+                matches!(probe.exit_reason, InstructionResult::OutOfGas)
+            } {
+                highest_gas_limit = highest_gas_limit.saturating_mul(2);
+            }
+
+            // `usize` covers every gas limit foundry can actually reach (64-bit targets only),
+            // so bisect directly in it instead of promoting through `u64`/`U256` on every
+            // iteration of the hot loop.
+            if let Some(found) = Self::bisect_gas_limit(
+                gas_used as usize,
+                highest_gas_limit as usize,
+                |mid_gas_limit| {
+                    self.executor.env.tx.gas_limit = mid_gas_limit as u64;
+                    let res = self.executor.call_raw(from, to, calldata.0.clone().into(), value)?;
+                    Ok(!matches!(
+                        res.exit_reason,
+                        InstructionResult::Revert |
+                            InstructionResult::OutOfGas |
+                            InstructionResult::OutOfFund
+                    ))
+                },
+            )? {
+                gas_used = found as u64;
             }
+
             // reset gas limit in the
             self.executor.env.tx.gas_limit = init_gas_limit;
         }
         Ok(gas_used)
     }
+
+    /// Seeds the upper bound for [`Self::search_optimal_gas_usage`]'s bisection.
+    ///
+    /// Per EIP-150, the outermost call can only forward `63/64` of its gas limit to subcalls, so
+    /// the true minimum limit for a call measured to use `gas_used` gas is much closer to
+    /// `gas_used * 64 / 63` (plus the 21000 intrinsic/base cost) than to `3 * gas_used`.
+    fn seed_high_gas_limit(gas_used: u64) -> u64 {
+        const BASE_TX_COST: u64 = 21_000;
+        gas_used * 64 / 63 + BASE_TX_COST
+    }
+
+    /// Narrows `[lowest, highest]` using `succeeds(mid)` (`Ok(true)` when `mid` gas is enough to
+    /// complete the call) until the bounds are 1 apart, short-circuiting once two consecutive
+    /// successful estimates are within `ACCURACY`% of each other.
+    ///
+    /// Returns `Ok(Some(gas))` if the accuracy short-circuit fired, or `Ok(None)` if the bounds
+    /// converged without ever doing so, in which case the caller should keep its original
+    /// `gas_used` estimate.
+    fn bisect_gas_limit(
+        lowest: usize,
+        highest: usize,
+        mut succeeds: impl FnMut(usize) -> Result<bool>,
+    ) -> Result<Option<usize>> {
+        let mut lowest_gas_limit = lowest;
+        let mut highest_gas_limit = highest;
+        let mut last_highest_gas_limit = highest;
+
+        while (highest_gas_limit - lowest_gas_limit) > 1 {
+            let mid_gas_limit = (highest_gas_limit + lowest_gas_limit) / 2;
+            if succeeds(mid_gas_limit)? {
+                highest_gas_limit = mid_gas_limit;
+                // if last two successful estimations only vary by 10%, we consider this to be
+                // sufficiently accurate
+                const ACCURACY: usize = 10;
+                if (last_highest_gas_limit - highest_gas_limit) * ACCURACY /
+                    last_highest_gas_limit <
+                    1
+                {
+                    return Ok(Some(highest_gas_limit));
+                }
+                last_highest_gas_limit = highest_gas_limit;
+            } else {
+                lowest_gas_limit = mid_gas_limit;
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deploy_failure_result_surfaces_traces_instead_of_panicking() {
+        let err = ExecutionErr {
+            gas_used: 12_345,
+            traces: Some(Default::default()),
+            ..Default::default()
+        };
+        let existing_traces: Traces = vec![];
+
+        let result = ScriptRunner::deploy_failure_result(existing_traces, err);
+
+        assert!(!result.success);
+        assert_eq!(result.gas_used, 12_345);
+        assert!(result.transactions.is_none());
+        assert!(matches!(result.traces.as_slice(), [(TraceKind::Deployment, _)]));
+    }
+
+    #[test]
+    fn zk_deps_to_prepare_filters_creates_within_depth() {
+        let queued = vec![
+            // index 0: a create, zk, within depth -> included.
+            QueuedZkTransaction {
+                to: None,
+                use_zk: true,
+                zk_tx: Some(ZkTransactionMetadata::default()),
+            },
+            // index 1: has a `to`, so it's a call, not a create -> excluded.
+            QueuedZkTransaction {
+                to: Some(Address::ZERO),
+                use_zk: true,
+                zk_tx: Some(ZkTransactionMetadata::default()),
+            },
+            // index 2: not a ZK transaction -> excluded.
+            QueuedZkTransaction {
+                to: None,
+                use_zk: false,
+                zk_tx: Some(ZkTransactionMetadata::default()),
+            },
+            // index 3: no factory deps to prepare -> excluded.
+            QueuedZkTransaction { to: None, use_zk: true, zk_tx: None },
+            // index 4: otherwise eligible, but past `depth` -> excluded.
+            QueuedZkTransaction {
+                to: None,
+                use_zk: true,
+                zk_tx: Some(ZkTransactionMetadata::default()),
+            },
+        ];
+
+        let prepared: Vec<&ZkTransactionMetadata> =
+            ScriptRunner::zk_deps_to_prepare(&queued, 4).collect();
+
+        assert_eq!(prepared.len(), 1);
+        assert!(std::ptr::eq(prepared[0], queued[0].zk_tx.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn seed_high_gas_limit_applies_the_63_64_rule_plus_base_cost() {
+        assert_eq!(ScriptRunner::seed_high_gas_limit(63_000), 63_000 * 64 / 63 + 21_000);
+        assert_eq!(ScriptRunner::seed_high_gas_limit(0), 21_000);
+    }
+
+    #[test]
+    fn bisect_gas_limit_finds_the_minimum_succeeding_gas() -> Result<()> {
+        // Anything below 1_000 reverts with OutOfGas; 1_000 and above succeeds.
+        let threshold = 1_000usize;
+        let found = ScriptRunner::bisect_gas_limit(100, 10_000, |mid| Ok(mid >= threshold))?;
+        let found = found.expect("accuracy short-circuit should fire well before convergence");
+        assert!(found >= threshold, "found {found} should be >= {threshold}");
+        assert!(
+            found - threshold <= threshold / 10,
+            "found {found} should be within 10% of {threshold}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bisect_gas_limit_returns_none_when_bounds_are_already_converged() -> Result<()> {
+        // `highest - lowest <= 1`, so the loop body never runs and nothing is returned.
+        let found = ScriptRunner::bisect_gas_limit(100, 101, |_| Ok(true))?;
+        assert_eq!(found, None);
+        Ok(())
+    }
+
+    #[test]
+    fn bisect_gas_limit_propagates_probe_errors() {
+        let result = ScriptRunner::bisect_gas_limit(100, 10_000, |_| eyre::bail!("probe failed"));
+        assert!(result.is_err());
+    }
 }